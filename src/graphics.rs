@@ -1,40 +1,148 @@
 // I used this guide for learning OpenGL in Rust: https://rust-tutorials.github.io/learn-opengl/introduction.html
 use glutin::{Context, PossiblyCurrent};
 use gl33::*;
+use std::marker::PhantomData;
+use std::rc::Rc;
 
 type GLuint = u32;
 
-type Vertex = [f32; 3];
-type TriIndexes = [u32; 3];
+/// Marker that makes a handle `!Send`/`!Sync`.
+///
+/// Deleting these handles requires calling back into the `GL` context that
+/// created them, and a GL context is only ever current on the thread that
+/// made it. A raw pointer isn't `Send`/`Sync`, so embedding one here stops a
+/// handle from being moved to (and deleted from) the wrong thread.
+type NotSendSync = PhantomData<*const u8>;
+
+/// Errors that can occur while standing up or driving the GL context, from
+/// window/context creation through shader compilation and program linking.
+#[derive(Debug)]
+pub enum GlError {
+    /// Couldn't allocate a new vertex array object.
+    VertexArrayAlloc,
+    /// Couldn't allocate a new buffer object.
+    BufferAlloc,
+    /// Couldn't allocate a new texture object.
+    TextureAlloc,
+    /// Couldn't allocate a new shader object.
+    ShaderAlloc,
+    /// Couldn't allocate a new program object.
+    ProgramAlloc,
+    /// A shader failed to compile.
+    CompileError { ty: ShaderType, log: String },
+    /// A program failed to link.
+    LinkError(String),
+    /// Failed to create a windowed GL context.
+    GlutinCreation(glutin::CreationError),
+    /// An operation on a GL context failed.
+    GlutinContext(glutin::ContextError),
+    /// Failed to load the GL function pointers for the current context.
+    FunctionLoad(String),
+}
+
+impl std::fmt::Display for GlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VertexArrayAlloc => write!(f, "couldn't allocate a new vertex array object"),
+            Self::BufferAlloc => write!(f, "couldn't allocate a new buffer object"),
+            Self::TextureAlloc => write!(f, "couldn't allocate a new texture object"),
+            Self::ShaderAlloc => write!(f, "couldn't allocate a new shader"),
+            Self::ProgramAlloc => write!(f, "couldn't allocate a new program"),
+            Self::CompileError { ty, log } => {
+                write!(f, "{:?} shader failed to compile: {}", ty, log)
+            }
+            Self::LinkError(log) => write!(f, "program failed to link: {}", log),
+            Self::GlutinCreation(e) => write!(f, "failed to create GL context: {}", e),
+            Self::GlutinContext(e) => write!(f, "GL context error: {}", e),
+            Self::FunctionLoad(log) => write!(f, "failed to load GL functions: {}", log),
+        }
+    }
+}
+
+impl std::error::Error for GlError {}
+
+impl From<glutin::CreationError> for GlError {
+    fn from(e: glutin::CreationError) -> Self {
+        Self::GlutinCreation(e)
+    }
+}
+
+impl From<glutin::ContextError> for GlError {
+    fn from(e: glutin::ContextError) -> Self {
+        Self::GlutinContext(e)
+    }
+}
 
-const VERTICES: [Vertex; 4] =
-    [[0.5, 0.5, 0.0], [0.5, -0.5, 0.0], [-0.5, -0.5, 0.0], [-0.5, 0.5, 0.0]];
+/// Maps a `glGetError` code to its readable constant name, for debug output.
+fn gl_error_name(code: GLenum) -> &'static str {
+    if code == GL_INVALID_ENUM {
+        "GL_INVALID_ENUM"
+    } else if code == GL_INVALID_VALUE {
+        "GL_INVALID_VALUE"
+    } else if code == GL_INVALID_OPERATION {
+        "GL_INVALID_OPERATION"
+    } else if code == GL_INVALID_FRAMEBUFFER_OPERATION {
+        "GL_INVALID_FRAMEBUFFER_OPERATION"
+    } else if code == GL_OUT_OF_MEMORY {
+        "GL_OUT_OF_MEMORY"
+    } else if code == GL_STACK_UNDERFLOW {
+        "GL_STACK_UNDERFLOW"
+    } else if code == GL_STACK_OVERFLOW {
+        "GL_STACK_OVERFLOW"
+    } else {
+        "UNKNOWN_GL_ERROR"
+    }
+}
+
+/// A single vertex: a clip-space-ish position plus a texture coordinate.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Vertex {
+    pub pos: [f32; 3],
+    pub uv: [f32; 2],
+}
 
-const INDICES: [TriIndexes; 2] = [[0, 1, 3], [1, 2, 3]];
+// Safe because `Vertex` is `repr(C)` and made up entirely of `f32`s with no
+// padding, so any bit pattern is a valid value and there are no invalid
+// byte ranges for `bytemuck::cast_slice` to read past.
+unsafe impl bytemuck::Zeroable for Vertex {}
+unsafe impl bytemuck::Pod for Vertex {}
+
+type TriIndexes = [u32; 3];
 
 const VERT_SHADER: &str = r#"#version 330 core
         layout (location = 0) in vec3 pos;
+        layout (location = 1) in vec2 uv;
+        out vec2 vary_uv;
         void main() {
             gl_Position = vec4(pos.x, pos.y, pos.z, 1.0);
+            vary_uv = uv;
         }
         "#;
 
 const FRAG_SHADER: &str = r#"#version 330 core
+        in vec2 vary_uv;
         out vec4 final_color;
 
+        uniform sampler2D uni_texture;
+
         void main() {
-            final_color = vec4(1.0, 0.5, 0.2, 1.0);
+            final_color = texture2D(uni_texture, vary_uv);
         }
 "#;
 
-pub struct VertexArray(pub GLuint);
+pub struct VertexArray {
+    id: GLuint,
+    gl: Rc<GlFns>,
+    _marker: NotSendSync,
+}
 impl VertexArray {
     /// Creates a new vertex array object
     pub fn new(ctx: &GL) -> Option<Self> {
         let mut vao = 0;
         unsafe { ctx.gl.GenVertexArrays(1, &mut vao) };
         if vao != 0 {
-            Some(Self(vao))
+            Some(Self { id: vao, gl: ctx.gl.clone(), _marker: PhantomData })
         } else {
             None
         }
@@ -42,7 +150,7 @@ impl VertexArray {
 
     /// Bind this vertex array as the current vertex array object
     pub fn bind(&self, ctx: &GL) {
-        unsafe { ctx.gl.BindVertexArray(self.0) }
+        unsafe { ctx.gl.BindVertexArray(self.id) }
     }
 
     /// Clear the current vertex array object binding.
@@ -51,6 +159,12 @@ impl VertexArray {
     }
 }
 
+impl Drop for VertexArray {
+    fn drop(&mut self) {
+        unsafe { self.gl.DeleteVertexArrays(1, &self.id) };
+    }
+}
+
 pub enum BufferType {
     Array,
     ElementArray,
@@ -66,7 +180,12 @@ impl BufferType {
     }
 }
 
-pub struct Buffer(pub GLuint, pub BufferType);
+pub struct Buffer {
+    id: GLuint,
+    ty: BufferType,
+    gl: Rc<GlFns>,
+    _marker: NotSendSync,
+}
 
 impl Buffer {
     /// Initialize a new buffer object
@@ -74,14 +193,14 @@ impl Buffer {
         let mut bo = 0;
         unsafe { ctx.gl.GenBuffers(1, &mut bo); }
         if bo != 0 {
-            Some(Self(bo, buffer_type))
+            Some(Self { id: bo, ty: buffer_type, gl: ctx.gl.clone(), _marker: PhantomData })
         } else {
             None
         }
     }
     /// Bind this buffer to the GL context
     pub fn bind(&self, ctx: &GL) {
-        unsafe { ctx.gl.BindBuffer(self.1.glenum(), self.0) }
+        unsafe { ctx.gl.BindBuffer(self.ty.glenum(), self.id) }
     }
     /// Clear the specified buffer type from the GL buffer binding.
     pub fn clear_binding(ctx: &GL, buffer_type: BufferType) {
@@ -89,6 +208,12 @@ impl Buffer {
     }
 }
 
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        unsafe { self.gl.DeleteBuffers(1, &self.id) };
+    }
+}
+
 /// Places a slice of data into a previously-bound buffer.
 pub fn buffer_data(ctx: &GL, ty: BufferType, data: &[u8], usage: GLenum) {
     unsafe {
@@ -99,9 +224,11 @@ pub fn buffer_data(ctx: &GL, ty: BufferType, data: &[u8], usage: GLenum) {
             usage,
         );
     }
+    ctx.check_errors_if_enabled("buffer_data");
 }
 
 /// The types of shader object.
+#[derive(Debug, Clone, Copy)]
 pub enum ShaderType {
     /// Vertex shaders determine the position of geometry within the screen.
     Vertex,
@@ -123,7 +250,11 @@ impl ShaderType {
 
 /// A handle to a [Shader
 /// Object](https://www.khronos.org/opengl/wiki/GLSL_Object#Shader_objects)
-pub struct Shader(pub GLuint);
+pub struct Shader {
+    id: GLuint,
+    gl: Rc<GlFns>,
+    _marker: NotSendSync,
+}
 
 impl Shader {
     /// Makes a new shader.
@@ -135,7 +266,7 @@ impl Shader {
     pub fn new(ctx: &GL, ty: ShaderType) -> Option<Self> {
         let shader = unsafe { ctx.gl.CreateShader(ty.glenum()) };
         if shader != 0 {
-            Some(Self(shader))
+            Some(Self { id: shader, gl: ctx.gl.clone(), _marker: PhantomData })
         } else {
             None
         }
@@ -147,7 +278,7 @@ impl Shader {
     pub fn set_source(&self, ctx: &GL, src: &str) {
         unsafe {
             ctx.gl.ShaderSource(
-                self.0,
+                self.id,
                 1,
                 &(src.as_bytes().as_ptr().cast()),
                 &(src.len().try_into().unwrap()),
@@ -157,13 +288,13 @@ impl Shader {
 
     /// Compiles the shader based on the current source.
     pub fn compile(&self, ctx: &GL) {
-        unsafe { ctx.gl.CompileShader(self.0) };
+        unsafe { ctx.gl.CompileShader(self.id) };
     }
 
     /// Checks if the last compile was successful or not.
     pub fn compile_success(&self, ctx: &GL) -> bool {
         let mut compiled = 0;
-        unsafe { ctx.gl.GetShaderiv(self.0, GL_COMPILE_STATUS, &mut compiled) };
+        unsafe { ctx.gl.GetShaderiv(self.id, GL_COMPILE_STATUS, &mut compiled) };
         compiled != 0
     }
 
@@ -172,12 +303,12 @@ impl Shader {
     /// Usually you use this to get the compilation log when a compile failed.
     pub fn info_log(&self, ctx: &GL) -> String {
         let mut needed_len = 0;
-        unsafe { ctx.gl.GetShaderiv(self.0, GL_INFO_LOG_LENGTH, &mut needed_len) };
+        unsafe { ctx.gl.GetShaderiv(self.id, GL_INFO_LOG_LENGTH, &mut needed_len) };
         let mut v: Vec<u8> = Vec::with_capacity(needed_len.try_into().unwrap());
         let mut len_written = 0_i32;
         unsafe {
             ctx.gl.GetShaderInfoLog(
-                self.0,
+                self.id,
                 v.capacity().try_into().unwrap(),
                 &mut len_written,
                 v.as_mut_ptr().cast(),
@@ -187,39 +318,41 @@ impl Shader {
         String::from_utf8_lossy(&v).into_owned()
     }
 
-    /// Marks a shader for deletion.
-    ///
-    /// Note: This _does not_ immediately delete the shader. It only marks it for
-    /// deletion. If the shader has been previously attached to a program then the
-    /// shader will stay allocated until it's unattached from that program.
-    pub fn delete(self, ctx: &GL) {
-        unsafe { ctx.gl.DeleteShader(self.0) };
-    }
-
     /// Takes a shader type and source string and produces either the compiled
     /// shader or an error message.
     ///
     /// Prefer [`ShaderProgram::from_vert_frag`](ShaderProgram::from_vert_frag),
     /// it makes a complete program from the vertex and fragment sources all at
     /// once.
-    pub fn from_source(ctx: &GL, ty: ShaderType, source: &str) -> Result<Self, String> {
-        let id = Self::new(ctx, ty)
-            .ok_or_else(|| "Couldn't allocate new shader".to_string())?;
+    pub fn from_source(ctx: &GL, ty: ShaderType, source: &str) -> Result<Self, GlError> {
+        let id = Self::new(ctx, ty).ok_or(GlError::ShaderAlloc)?;
         id.set_source(ctx, source);
         id.compile(ctx);
         if id.compile_success(ctx) {
             Ok(id)
         } else {
-            let out = id.info_log(ctx);
-            id.delete(ctx);
-            Err(out)
+            let log = id.info_log(ctx);
+            Err(GlError::CompileError { ty, log })
         }
     }
 }
 
+impl Drop for Shader {
+    fn drop(&mut self) {
+        // Note: This _does not_ immediately delete the shader. It only marks it
+        // for deletion. If the shader is still attached to a program, it stays
+        // allocated until it's unattached from that program.
+        unsafe { self.gl.DeleteShader(self.id) };
+    }
+}
+
 /// A handle to a [Program
 /// Object](https://www.khronos.org/opengl/wiki/GLSL_Object#Program_objects)
-pub struct ShaderProgram(pub GLuint);
+pub struct ShaderProgram {
+    id: GLuint,
+    gl: Rc<GlFns>,
+    _marker: NotSendSync,
+}
 impl ShaderProgram {
     /// Allocates a new program object.
     ///
@@ -229,7 +362,7 @@ impl ShaderProgram {
     pub fn new(ctx: &GL) -> Option<Self> {
         let prog = unsafe { ctx.gl.CreateProgram() };
         if prog != 0 {
-            Some(Self(prog))
+            Some(Self { id: prog, gl: ctx.gl.clone(), _marker: PhantomData })
         } else {
             None
         }
@@ -237,18 +370,19 @@ impl ShaderProgram {
 
     /// Attaches a shader object to this program object.
     pub fn attach_shader(&self, ctx: &GL, shader: &Shader) {
-        unsafe { ctx.gl.AttachShader(self.0, shader.0) };
+        unsafe { ctx.gl.AttachShader(self.id, shader.id) };
     }
 
     /// Links the various attached, compiled shader objects into a usable program.
     pub fn link_program(&self, ctx: &GL) {
-        unsafe { ctx.gl.LinkProgram(self.0) };
+        unsafe { ctx.gl.LinkProgram(self.id) };
+        ctx.check_errors_if_enabled("link_program");
     }
 
     /// Checks if the last linking operation was successful.
     pub fn link_success(&self, ctx: &GL) -> bool {
         let mut success = 0;
-        unsafe { ctx.gl.GetProgramiv(self.0, GL_LINK_STATUS, &mut success) };
+        unsafe { ctx.gl.GetProgramiv(self.id, GL_LINK_STATUS, &mut success) };
         success != 0
     }
 
@@ -257,12 +391,12 @@ impl ShaderProgram {
     /// This is usually used to check the message when a program failed to link.
     pub fn info_log(&self, ctx: &GL) -> String {
         let mut needed_len = 0;
-        unsafe { ctx.gl.GetProgramiv(self.0, GL_INFO_LOG_LENGTH, &mut needed_len) };
+        unsafe { ctx.gl.GetProgramiv(self.id, GL_INFO_LOG_LENGTH, &mut needed_len) };
         let mut v: Vec<u8> = Vec::with_capacity(needed_len.try_into().unwrap());
         let mut len_written = 0_i32;
         unsafe {
             ctx.gl.GetProgramInfoLog(
-                self.0,
+                self.id,
                 v.capacity().try_into().unwrap(),
                 &mut len_written,
                 v.as_mut_ptr().cast(),
@@ -274,16 +408,50 @@ impl ShaderProgram {
 
     /// Sets the program as the program to use when drawing.
     pub fn use_program(&self, ctx: &GL) {
-        unsafe { ctx.gl.UseProgram(self.0) };
+        unsafe { ctx.gl.UseProgram(self.id) };
+    }
+
+    /// Looks up the location of a uniform variable by name.
+    ///
+    /// Returns `None` if the uniform isn't an active uniform in this program
+    /// (for example, if the compiler optimized it out because it's unused).
+    pub fn get_uniform_location(&self, ctx: &GL, name: &str) -> Option<i32> {
+        let c_name = std::ffi::CString::new(name).unwrap();
+        let loc = unsafe { ctx.gl.GetUniformLocation(self.id, c_name.as_ptr().cast()) };
+        if loc == -1 {
+            None
+        } else {
+            Some(loc)
+        }
+    }
+
+    /// Sets a single `float` uniform.
+    ///
+    /// Assumes this program is already bound via [`use_program`](Self::use_program).
+    pub fn set_uniform_f32(&self, ctx: &GL, loc: i32, value: f32) {
+        unsafe { ctx.gl.Uniform1f(loc, value) };
+    }
+
+    /// Sets a `vec3` uniform.
+    ///
+    /// Assumes this program is already bound via [`use_program`](Self::use_program).
+    pub fn set_uniform_vec3(&self, ctx: &GL, loc: i32, value: [f32; 3]) {
+        unsafe { ctx.gl.Uniform3f(loc, value[0], value[1], value[2]) };
     }
 
-    /// Marks the program for deletion.
+    /// Sets a `mat4` uniform from a column-major array of 16 floats.
     ///
-    /// Note: This _does not_ immediately delete the program. If the program is
-    /// currently in use it won't be deleted until it's not the active program.
-    /// When a program is finally deleted and attached shaders are unattached.
-    pub fn delete(self, ctx: &GL) {
-        unsafe { ctx.gl.DeleteProgram(self.0) };
+    /// Assumes this program is already bound via [`use_program`](Self::use_program).
+    pub fn set_uniform_mat4(&self, ctx: &GL, loc: i32, value: &[f32; 16]) {
+        unsafe { ctx.gl.UniformMatrix4fv(loc, 1, false as u8, value.as_ptr()) };
+    }
+
+    /// Sets a single `int` uniform, typically used to bind a texture sampler
+    /// to a texture unit slot.
+    ///
+    /// Assumes this program is already bound via [`use_program`](Self::use_program).
+    pub fn set_uniform_i32(&self, ctx: &GL, loc: i32, value: i32) {
+        unsafe { ctx.gl.Uniform1i(loc, value) };
     }
 
     /// Takes a vertex shader source string and a fragment shader source string
@@ -291,45 +459,314 @@ impl ShaderProgram {
     ///
     /// This is the preferred way to create a simple shader program in the common
     /// case. It's just less error prone than doing all the steps yourself.
-    pub fn from_vert_frag(ctx: &GL, vert: &str, frag: &str) -> Result<Self, String> {
-        let p =
-            Self::new(ctx).ok_or_else(|| "Couldn't allocate a program".to_string())?;
-        let v = Shader::from_source(ctx, ShaderType::Vertex, vert)
-            .map_err(|e| format!("Vertex Compile Error: {}", e))?;
-        let f = Shader::from_source(ctx, ShaderType::Fragment, frag)
-            .map_err(|e| format!("Fragment Compile Error: {}", e))?;
+    pub fn from_vert_frag(ctx: &GL, vert: &str, frag: &str) -> Result<Self, GlError> {
+        let p = Self::new(ctx).ok_or(GlError::ProgramAlloc)?;
+        let v = Shader::from_source(ctx, ShaderType::Vertex, vert)?;
+        let f = Shader::from_source(ctx, ShaderType::Fragment, frag)?;
         p.attach_shader(ctx, &v);
         p.attach_shader(ctx, &f);
         p.link_program(ctx);
-        v.delete(ctx);
-        f.delete(ctx);
+        drop(v);
+        drop(f);
         if p.link_success(ctx) {
             Ok(p)
         } else {
-            let out = format!("Program Link Error: {}", p.info_log(ctx));
-            p.delete(ctx);
-            Err(out)
+            Err(GlError::LinkError(p.info_log(ctx)))
         }
     }
 }
 
+impl Drop for ShaderProgram {
+    fn drop(&mut self) {
+        // Note: This _does not_ immediately delete the program. If the program
+        // is currently in use it won't be deleted until it's not the active
+        // program. Once a program is finally deleted, attached shaders are
+        // unattached.
+        unsafe { self.gl.DeleteProgram(self.id) };
+    }
+}
+
+/// A handle to a [Texture
+/// Object](https://www.khronos.org/opengl/wiki/Texture), bound to the
+/// `GL_TEXTURE_2D` target.
+pub struct Texture {
+    id: GLuint,
+    /// Pixel width of the uploaded image. `0` until [`from_image`](Self::from_image).
+    pub width: u32,
+    /// Pixel height of the uploaded image. `0` until [`from_image`](Self::from_image).
+    pub height: u32,
+    gl: Rc<GlFns>,
+    _marker: NotSendSync,
+}
+
+impl Texture {
+    /// Allocates a new, empty texture object.
+    ///
+    /// Prefer [`Texture::from_image`](Texture::from_image), it also uploads
+    /// pixel data and sets sensible sampling parameters.
+    pub fn new(ctx: &GL) -> Option<Self> {
+        let mut tex = 0;
+        unsafe { ctx.gl.GenTextures(1, &mut tex) };
+        if tex != 0 {
+            Some(Self { id: tex, width: 0, height: 0, gl: ctx.gl.clone(), _marker: PhantomData })
+        } else {
+            None
+        }
+    }
+
+    /// Bind this texture to the `GL_TEXTURE_2D` target.
+    pub fn bind(&self, ctx: &GL) {
+        unsafe { ctx.gl.BindTexture(GL_TEXTURE_2D, self.id) }
+    }
+
+    /// Clear the `GL_TEXTURE_2D` binding.
+    pub fn clear_binding(ctx: &GL) {
+        unsafe { ctx.gl.BindTexture(GL_TEXTURE_2D, 0) }
+    }
+
+    /// Uploads a tightly packed `width * height` RGBA8 image and sets
+    /// nearest-neighbor filtering with edge clamping, which suits pixel art
+    /// tilemaps better than the GL defaults.
+    pub fn from_image(ctx: &GL, width: u32, height: u32, rgba: &[u8]) -> Option<Self> {
+        let mut tex = Self::new(ctx)?;
+        tex.bind(ctx);
+        unsafe {
+            ctx.gl.TexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_S, GL_CLAMP_TO_EDGE.0 as i32);
+            ctx.gl.TexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_T, GL_CLAMP_TO_EDGE.0 as i32);
+            ctx.gl.TexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MIN_FILTER, GL_NEAREST.0 as i32);
+            ctx.gl.TexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MAG_FILTER, GL_NEAREST.0 as i32);
+            ctx.gl.TexImage2D(
+                GL_TEXTURE_2D,
+                0,
+                GL_RGBA.0 as i32,
+                width as i32,
+                height as i32,
+                0,
+                GL_RGBA,
+                GL_UNSIGNED_BYTE,
+                rgba.as_ptr().cast(),
+            );
+        }
+        Texture::clear_binding(ctx);
+        tex.width = width;
+        tex.height = height;
+        Some(tex)
+    }
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        unsafe { self.gl.DeleteTextures(1, &self.id) };
+    }
+}
+
+/// One [`Texture`] sliced into a uniform grid of `tile_width` by
+/// `tile_height` tiles, addressed by `(col, row)`.
+pub struct Spritesheet {
+    pub texture: Texture,
+    pub tile_width: u32,
+    pub tile_height: u32,
+}
+
+impl Spritesheet {
+    /// Wraps `texture` as a sheet of `tile_width` by `tile_height` tiles.
+    ///
+    /// `texture` must have been built with [`Texture::from_image`] so its
+    /// pixel dimensions are known.
+    pub fn new(texture: Texture, tile_width: u32, tile_height: u32) -> Self {
+        Self { texture, tile_width, tile_height }
+    }
+
+    fn columns(&self) -> u32 {
+        self.texture.width / self.tile_width
+    }
+
+    fn rows(&self) -> u32 {
+        self.texture.height / self.tile_height
+    }
+
+    /// Maps a `(col, row)` tile index to its `[u_min, v_min, u_max, v_max]`
+    /// UV sub-rectangle.
+    ///
+    /// `(0, 0)` is the tile at the top-left of the sheet, i.e. the first
+    /// pixel rows passed to [`Texture::from_image`]. `TexImage2D` stores the
+    /// first uploaded row at `V = 0`, so row `r` covers `V` from `r / rows`
+    /// to `(r + 1) / rows`.
+    pub fn uv_rect(&self, col: u32, row: u32) -> [f32; 4] {
+        let columns = self.columns() as f32;
+        let rows = self.rows() as f32;
+        let u_min = col as f32 / columns;
+        let u_max = (col + 1) as f32 / columns;
+        let v_min = row as f32 / rows;
+        let v_max = (row + 1) as f32 / rows;
+        [u_min, v_min, u_max, v_max]
+    }
+}
+
+/// A single cell of a [`Board`]: which tile of its [`Spritesheet`] to show.
+#[derive(Debug, Clone, Copy)]
+pub struct Tile {
+    pub col: u32,
+    pub row: u32,
+}
+
+/// A 2D grid of [`Tile`]s, all drawn from one shared [`Spritesheet`].
+pub struct Board {
+    width: u32,
+    height: u32,
+    tiles: Vec<Tile>,
+}
+
+impl Board {
+    /// Creates a `width` by `height` board with every cell set to `fill`.
+    pub fn new(width: u32, height: u32, fill: Tile) -> Self {
+        Self { width, height, tiles: vec![fill; (width * height) as usize] }
+    }
+
+    pub fn set(&mut self, col: u32, row: u32, tile: Tile) {
+        self.tiles[(row * self.width + col) as usize] = tile;
+    }
+
+    pub fn get(&self, col: u32, row: u32) -> Tile {
+        self.tiles[(row * self.width + col) as usize]
+    }
+
+    /// Builds vertex and index data for drawing every cell of this board as
+    /// a unit quad, with each quad's UVs taken from `spritesheet`.
+    ///
+    /// Cell `(0, 0)` occupies `[0, 1] x [0, 1]` in board space, with later
+    /// columns/rows extending along `+x`/`+y`.
+    pub fn to_mesh(&self, spritesheet: &Spritesheet) -> (Vec<Vertex>, Vec<TriIndexes>) {
+        let mut vertices = Vec::with_capacity(self.tiles.len() * 4);
+        let mut indices = Vec::with_capacity(self.tiles.len() * 2);
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let tile = self.get(col, row);
+                let [u_min, v_min, u_max, v_max] = spritesheet.uv_rect(tile.col, tile.row);
+                let x0 = col as f32;
+                let x1 = (col + 1) as f32;
+                let y0 = row as f32;
+                let y1 = (row + 1) as f32;
+                let base = vertices.len() as u32;
+                vertices.push(Vertex { pos: [x0, y0, 0.0], uv: [u_min, v_min] });
+                vertices.push(Vertex { pos: [x1, y0, 0.0], uv: [u_max, v_min] });
+                vertices.push(Vertex { pos: [x1, y1, 0.0], uv: [u_max, v_max] });
+                vertices.push(Vertex { pos: [x0, y1, 0.0], uv: [u_min, v_max] });
+                indices.push([base, base + 1, base + 3]);
+                indices.push([base + 1, base + 2, base + 3]);
+            }
+        }
+        (vertices, indices)
+    }
+}
+
+/// Pixel size of one tile in the demo spritesheet built by [`GL::setup`].
+const DEMO_TILE_PX: u32 = 8;
+/// Size, in tiles, of the demo [`Board`] built by [`GL::setup`].
+const DEMO_BOARD_COLS: u32 = 4;
+const DEMO_BOARD_ROWS: u32 = 3;
+
+/// Builds a tiny two-tile spritesheet image: tile `(0, 0)` is solid orange
+/// (the color the old hardcoded demo quad used), tile `(1, 0)` is dark gray.
+fn demo_spritesheet_pixels() -> Vec<u8> {
+    let sheet_width = DEMO_TILE_PX * 2;
+    let sheet_height = DEMO_TILE_PX;
+    let mut pixels = vec![0u8; (sheet_width * sheet_height * 4) as usize];
+    for y in 0..sheet_height {
+        for x in 0..sheet_width {
+            let rgba: [u8; 4] =
+                if x < DEMO_TILE_PX { [255, 128, 51, 255] } else { [26, 26, 26, 255] };
+            let idx = ((y * sheet_width + x) * 4) as usize;
+            pixels[idx..idx + 4].copy_from_slice(&rgba);
+        }
+    }
+    pixels
+}
+
 // OpenGL wrapper
 pub struct GL {
-    pub gl: GlFns
+    pub gl: Rc<GlFns>,
+    // Kept alive for as long as the GL wrapper is, so that the RAII Drop
+    // impls on these handles don't delete them out from under us the moment
+    // `setup` returns.
+    vao: Option<VertexArray>,
+    vbo: Option<Buffer>,
+    ebo: Option<Buffer>,
+    shader_program: Option<ShaderProgram>,
+    spritesheet: Option<Spritesheet>,
+    // Number of indices uploaded to `ebo`, i.e. the `count` `draw_frame`
+    // passes to `DrawElements`.
+    index_count: i32,
+    // Runtime opt-in for the automatic `glGetError` checks threaded through
+    // `setup`/`draw_frame`. Checked on top of `debug_assertions`, so release
+    // builds never pay for it even if a caller forgets to turn it off.
+    check_errors_enabled: bool,
 }
 
 impl GL {
-    pub fn new(ctx: &Context<PossiblyCurrent>) -> Self {
+    pub fn new(ctx: &Context<PossiblyCurrent>) -> Result<Self, GlError> {
         let gl = unsafe {
             GlFns::load_from(&|p| {
                 let c_str = std::ffi::CStr::from_ptr(p.cast());
                 let rust_str = c_str.to_str().unwrap();
                 ctx.get_proc_address(rust_str) as _
             })
-                .unwrap()
+                .map_err(GlError::FunctionLoad)?
         };
 
-        Self { gl }
+        Ok(Self {
+            gl: Rc::new(gl),
+            vao: None,
+            vbo: None,
+            ebo: None,
+            shader_program: None,
+            spritesheet: None,
+            index_count: 0,
+            check_errors_enabled: false,
+        })
+    }
+
+    /// Enables or disables the automatic `glGetError` checks that run after
+    /// key operations in [`setup`](Self::setup) and
+    /// [`draw_frame`](Self::draw_frame).
+    ///
+    /// These checks only ever run in debug builds, regardless of this flag.
+    pub fn set_error_checking(&mut self, enabled: bool) {
+        self.check_errors_enabled = enabled;
+    }
+
+    /// Drains the GL error queue, returning every pending error code.
+    ///
+    /// Drivers can queue up more than one error between checks, so a single
+    /// `glGetError` call isn't enough to know the queue is clear; this keeps
+    /// calling it until it reports `GL_NO_ERROR`.
+    pub fn check_errors(&self, label: &str) -> Result<(), Vec<GLenum>> {
+        let mut errors = Vec::new();
+        loop {
+            let code = unsafe { self.gl.GetError() };
+            if code == GL_NO_ERROR {
+                break;
+            }
+            errors.push(code);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            eprintln!(
+                "[{}] GL error(s): {}",
+                label,
+                errors.iter().map(|&e| gl_error_name(e)).collect::<Vec<_>>().join(", "),
+            );
+            Err(errors)
+        }
+    }
+
+    /// Runs [`check_errors`](Self::check_errors) when debug assertions are on
+    /// and [`set_error_checking`](Self::set_error_checking) has been enabled.
+    fn check_errors_if_enabled(&self, label: &str) {
+        if cfg!(debug_assertions) && self.check_errors_enabled {
+            let _ = self.check_errors(label);
+        }
     }
 
     pub fn clear(&self) {
@@ -340,22 +777,42 @@ impl GL {
         unsafe { self.gl.ClearColor(r, g, b, a); }
     }
 
-    pub fn setup(&self) {
+    pub fn setup(&mut self) -> Result<(), GlError> {
         self.clear_color(0.1, 0.1, 0.1, 1.0);
-        let vao = VertexArray::new(self).unwrap();
+        let vao = VertexArray::new(self).ok_or(GlError::VertexArrayAlloc)?;
         vao.bind(self);
-        let vbo = Buffer::new(self, BufferType::Array).unwrap();
+        let vbo = Buffer::new(self, BufferType::Array).ok_or(GlError::BufferAlloc)?;
         vbo.bind(self);
-        let ebo = Buffer::new(self, BufferType::ElementArray).unwrap();
+        let ebo = Buffer::new(self, BufferType::ElementArray).ok_or(GlError::BufferAlloc)?;
         ebo.bind(self);
-        buffer_data(
+
+        let texture = Texture::from_image(
             self,
-            BufferType::ElementArray,
-            bytemuck::cast_slice(&INDICES),
-            GL_STATIC_DRAW,
-        );
+            DEMO_TILE_PX * 2,
+            DEMO_TILE_PX,
+            &demo_spritesheet_pixels(),
+        )
+        .ok_or(GlError::TextureAlloc)?;
+        let spritesheet = Spritesheet::new(texture, DEMO_TILE_PX, DEMO_TILE_PX);
+
+        let mut board = Board::new(DEMO_BOARD_COLS, DEMO_BOARD_ROWS, Tile { col: 0, row: 0 });
+        for row in 0..DEMO_BOARD_ROWS {
+            for col in 0..DEMO_BOARD_COLS {
+                if (row + col) % 2 == 1 {
+                    board.set(col, row, Tile { col: 1, row: 0 });
+                }
+            }
+        }
+        let (mut vertices, indices) = board.to_mesh(&spritesheet);
+        // `to_mesh` lays the board out in `[0, cols] x [0, rows]` tile
+        // units; remap that to clip space so it fills the window.
+        for v in &mut vertices {
+            v.pos[0] = (v.pos[0] / DEMO_BOARD_COLS as f32) * 2.0 - 1.0;
+            v.pos[1] = (v.pos[1] / DEMO_BOARD_ROWS as f32) * 2.0 - 1.0;
+        }
 
-        buffer_data(self, BufferType::Array, bytemuck::cast_slice(&VERTICES), GL_STATIC_DRAW);
+        buffer_data(self, BufferType::ElementArray, bytemuck::cast_slice(&indices), GL_STATIC_DRAW);
+        buffer_data(self, BufferType::Array, bytemuck::cast_slice(&vertices), GL_STATIC_DRAW);
 
         unsafe {
             self.gl.VertexAttribPointer(
@@ -367,16 +824,38 @@ impl GL {
                 0 as *const _,
             );
             self.gl.EnableVertexAttribArray(0);
+
+            self.gl.VertexAttribPointer(
+                1,
+                2,
+                GL_FLOAT,
+                false as u8,
+                core::mem::size_of::<Vertex>().try_into().unwrap(),
+                core::mem::size_of::<[f32; 3]>() as *const _,
+            );
+            self.gl.EnableVertexAttribArray(1);
         }
-        let shader_program =
-            ShaderProgram::from_vert_frag(self, VERT_SHADER, FRAG_SHADER).unwrap();
+        let shader_program = ShaderProgram::from_vert_frag(self, VERT_SHADER, FRAG_SHADER)?;
         shader_program.use_program(self);
+        spritesheet.texture.bind(self);
+        if let Some(loc) = shader_program.get_uniform_location(self, "uni_texture") {
+            shader_program.set_uniform_i32(self, loc, 0);
+        }
+
+        self.index_count = (indices.len() * 3).try_into().unwrap();
+        self.vao = Some(vao);
+        self.vbo = Some(vbo);
+        self.ebo = Some(ebo);
+        self.shader_program = Some(shader_program);
+        self.spritesheet = Some(spritesheet);
+        Ok(())
     }
 
     pub fn draw_frame(&self) {
         unsafe {
             self.clear();
-            self.gl.DrawElements(GL_TRIANGLES, 6, GL_UNSIGNED_INT, 0 as *const _);
+            self.gl.DrawElements(GL_TRIANGLES, self.index_count, GL_UNSIGNED_INT, 0 as *const _);
         }
+        self.check_errors_if_enabled("DrawElements");
     }
 }
\ No newline at end of file
@@ -7,21 +7,20 @@ mod graphics;
 
 use graphics::*;
 
-fn main() {
+fn main() -> Result<(), GlError> {
     let el = glutin::event_loop::EventLoop::new();
     let wb = glutin::window::WindowBuilder::new()
         .with_title("Hello world!")
         .with_inner_size(glutin::dpi::LogicalSize::new(1024.0f32, 768.0f32));
     let windowed_context = glutin::ContextBuilder::new()
         .with_vsync(true)
-        .build_windowed(wb, &el)
-        .unwrap();
+        .build_windowed(wb, &el)?;
 
-    let windowed_context = unsafe { windowed_context.make_current().unwrap() };
+    let windowed_context = unsafe { windowed_context.make_current().map_err(|(_, e)| e)? };
 
-    let gl = GL::new(windowed_context.context());
+    let mut gl = GL::new(windowed_context.context())?;
 
-    gl.setup();
+    gl.setup()?;
 
     el.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;